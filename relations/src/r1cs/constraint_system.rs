@@ -1,5 +1,5 @@
 #[cfg(feature = "std")]
-use crate::r1cs::ConstraintTrace;
+use crate::r1cs::{ConstraintTrace, TraceStep};
 use crate::r1cs::{LcIndex, LinearCombination, Matrix, SynthesisError, Variable};
 use ark_ff::Field;
 use ark_std::{
@@ -587,6 +587,57 @@ impl<F: Field> ConstraintSystem<F> {
     /// the first unsatisfied constraint. If `self.is_in_setup_mode()`, outputs
     /// `Err(())`.
     pub fn which_is_unsatisfied(&self) -> crate::r1cs::Result<Option<String>> {
+        Ok(self.first_unsatisfied()?.map(|i| {
+            let trace;
+            #[cfg(feature = "std")]
+            {
+                trace = self.constraint_traces[i].as_ref().map_or_else(
+                    || {
+                        eprintln!("Constraint trace requires enabling `ConstraintLayer`");
+                        format!("{}", i)
+                    },
+                    |t| format!("{}", t),
+                );
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                trace = format!("{}", i);
+            }
+            trace
+        }))
+    }
+
+    /// If `self` is satisfied, outputs `Ok(())`.
+    /// If `self` is unsatisfied, outputs
+    /// `Err(SynthesisError::UnsatisfiableConstraint(i))`, where `i` is the
+    /// index of the first unsatisfied constraint. The namespaces in which it
+    /// was enforced can be obtained via `constraint_path(i)`.
+    /// If `self.is_in_setup_mode()`, outputs
+    /// `Err(SynthesisError::AssignmentMissing)`.
+    pub fn check_satisfied(&self) -> crate::r1cs::Result<()> {
+        match self.first_unsatisfied()? {
+            None => Ok(()),
+            Some(i) => Err(SynthesisError::UnsatisfiableConstraint(i)),
+        }
+    }
+
+    /// Outputs the path of `r1cs` spans, starting from the root, that were
+    /// active when the constraint with index `index` was enforced. Unlike
+    /// the innermost span name alone, this identifies the constraint even if
+    /// the same namespace name is used at several nesting levels.
+    ///
+    /// Outputs `None` if there is no such constraint, or if no trace was
+    /// captured for it (this requires enabling `ConstraintLayer`).
+    #[cfg(feature = "std")]
+    pub fn constraint_path(&self, index: usize) -> Option<Vec<TraceStep>> {
+        self.constraint_traces
+            .get(index)?
+            .as_ref()
+            .map(|trace| trace.path())
+    }
+
+    /// Outputs the index of the first unsatisfied constraint, if any.
+    fn first_unsatisfied(&self) -> crate::r1cs::Result<Option<usize>> {
         if self.is_in_setup_mode() {
             Err(SynthesisError::AssignmentMissing)
         } else {
@@ -601,28 +652,58 @@ impl<F: Field> ConstraintSystem<F> {
                     .eval_lc(self.c_constraints[i])
                     .ok_or(SynthesisError::AssignmentMissing)?;
                 if a * b != c {
-                    let trace;
-                    #[cfg(feature = "std")]
-                    {
-                        trace = self.constraint_traces[i].as_ref().map_or_else(
-                            || {
-                                eprintln!("Constraint trace requires enabling `ConstraintLayer`");
-                                format!("{}", i)
-                            },
-                            |t| format!("{}", t),
-                        );
-                    }
-                    #[cfg(not(feature = "std"))]
-                    {
-                        trace = format!("{}", i);
-                    }
-                    return Ok(Some(trace));
+                    return Ok(Some(i));
                 }
             }
             Ok(None)
         }
     }
 
+    /// The traces captured for each constraint, in the order in which the
+    /// constraints were enforced.
+    #[cfg(feature = "std")]
+    pub(crate) fn constraint_traces(&self) -> &[Option<ConstraintTrace>] {
+        &self.constraint_traces
+    }
+
+    /// For each witness variable, determine whether it appears with a nonzero
+    /// coefficient in the A, B, or C linear combination of some constraint,
+    /// following symbolic LCs to the variables they are built from.
+    ///
+    /// Returns `None` if `self` does not construct matrices, since the
+    /// constraints are then not recorded.
+    pub(crate) fn witness_usage(&self) -> Option<Vec<bool>> {
+        if !self.should_construct_matrices() {
+            return None;
+        }
+        let mut used = vec![false; self.num_witness_variables];
+        let mut visited = ark_std::collections::BTreeSet::new();
+        let mut to_visit: Vec<LcIndex> = self
+            .a_constraints
+            .iter()
+            .chain(&self.b_constraints)
+            .chain(&self.c_constraints)
+            .copied()
+            .collect();
+
+        while let Some(index) = to_visit.pop() {
+            if !visited.insert(index) {
+                continue;
+            }
+            for (coeff, var) in self.lc_map.get(&index).into_iter().flat_map(|lc| lc.iter()) {
+                if coeff.is_zero() {
+                    continue;
+                }
+                match var {
+                    Variable::Witness(i) => used[*i] = true,
+                    Variable::SymbolicLc(i) => to_visit.push(*i),
+                    _ => {},
+                }
+            }
+        }
+        Some(used)
+    }
+
     /// Obtain the assignment corresponding to the `Variable` `v`.
     pub fn assigned_value(&self, v: Variable) -> Option<F> {
         match v {
@@ -963,6 +1044,31 @@ impl<F: Field> ConstraintSystemRef<F> {
             })
     }
 
+    /// If `self` is satisfied, outputs `Ok(())`.
+    /// If `self` is unsatisfied, outputs
+    /// `Err(SynthesisError::UnsatisfiableConstraint(i))`, where `i` is the
+    /// index of the first unsatisfied constraint.
+    /// If `self.is_in_setup_mode()` or `self == None`, outputs
+    /// `Err(SynthesisError::AssignmentMissing)`.
+    pub fn check_satisfied(&self) -> crate::r1cs::Result<()> {
+        self.inner()
+            .map_or(Err(SynthesisError::AssignmentMissing), |cs| {
+                cs.borrow().check_satisfied()
+            })
+    }
+
+    /// Outputs the path of `r1cs` spans, starting from the root, that were
+    /// active when the constraint with index `index` was enforced.
+    ///
+    /// Outputs `None` if `self == None`, if there is no such constraint, or
+    /// if no trace was captured for it (this requires enabling
+    /// `ConstraintLayer`).
+    #[cfg(feature = "std")]
+    pub fn constraint_path(&self, index: usize) -> Option<Vec<TraceStep>> {
+        self.inner()
+            .and_then(|cs| cs.borrow().constraint_path(index))
+    }
+
     /// Obtain the assignment corresponding to the `Variable` `v`.
     pub fn assigned_value(&self, v: Variable) -> Option<F> {
         self.inner().and_then(|cs| cs.borrow().assigned_value(v))
@@ -1053,4 +1159,60 @@ mod tests {
         assert_eq!(matrices.c[2], vec![(two, 1), (two, 2)]);
         Ok(())
     }
+
+    #[test]
+    fn unsatisfied_constraint_index() -> crate::r1cs::Result<()> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let two = Fr::one() + Fr::one();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(two))?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + b)?;
+        assert_eq!(cs.check_satisfied(), Ok(()));
+
+        cs.enforce_constraint(lc!() + b, lc!() + b, lc!() + b)?;
+        cs.enforce_constraint(lc!() + a, lc!() + a, lc!() + b)?;
+        assert_eq!(
+            cs.check_satisfied(),
+            Err(SynthesisError::UnsatisfiableConstraint(1))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unsatisfied_constraint_path() -> crate::r1cs::Result<()> {
+        use crate::ns;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let subscriber = tracing_subscriber::Registry::default()
+            .with(ConstraintLayer::new(TracingMode::OnlyConstraints));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        {
+            let _outer = ns!(cs, "outer");
+            let ns = ns!(cs, "gadget");
+            ns.cs()
+                .enforce_constraint(lc!() + a, lc!() + a, lc!() + a)?;
+        }
+        {
+            let ns = ns!(cs, "gadget");
+            ns.cs()
+                .enforce_constraint(lc!() + a, lc!() + a, lc!() + Variable::Zero)?;
+        }
+        assert_eq!(
+            cs.check_satisfied(),
+            Err(SynthesisError::UnsatisfiableConstraint(1))
+        );
+
+        let names = |i| {
+            cs.constraint_path(i)
+                .map(|path| path.iter().map(|step| step.name).collect::<Vec<_>>())
+        };
+        assert_eq!(names(0), Some(vec!["outer", "gadget"]));
+        assert_eq!(names(1), Some(vec!["gadget"]));
+        assert_eq!(names(2), None);
+        Ok(())
+    }
 }
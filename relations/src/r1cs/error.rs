@@ -13,6 +13,9 @@ pub enum SynthesisError {
     DivisionByZero,
     /// During synthesis, we constructed an unsatisfiable constraint system.
     Unsatisfiable,
+    /// During satisfiability checking, the constraint with the given index was
+    /// not satisfied by the variable assignment.
+    UnsatisfiableConstraint(usize),
     /// During synthesis, our polynomials ended up being too high of degree
     PolynomialDegreeTooLarge,
     /// During proof generation, we encountered an identity in the CRS
@@ -34,6 +37,9 @@ impl fmt::Display for SynthesisError {
             }
             SynthesisError::DivisionByZero => write!(f, "division by zero"),
             SynthesisError::Unsatisfiable => write!(f, "unsatisfiable constraint system"),
+            SynthesisError::UnsatisfiableConstraint(i) => {
+                write!(f, "constraint {} is unsatisfied", i)
+            },
             SynthesisError::PolynomialDegreeTooLarge => write!(f, "polynomial degree is too large"),
             SynthesisError::UnexpectedIdentity => {
                 write!(f, "encountered an identity element in the CRS")
@@ -10,8 +10,13 @@ mod impl_lc;
 mod constraint_system;
 mod error;
 #[cfg(feature = "std")]
+mod profiler;
+#[cfg(feature = "std")]
 mod trace;
+mod unconstrained;
 
+#[cfg(feature = "std")]
+pub use crate::r1cs::profiler::{NamespaceProfile, NamespaceProfiler};
 #[cfg(feature = "std")]
 pub use crate::r1cs::trace::{ConstraintLayer, ConstraintTrace, TraceStep, TracingMode};
 
@@ -23,6 +28,7 @@ pub use constraint_system::{
     OptimizationGoal, SynthesisMode,
 };
 pub use error::SynthesisError;
+pub use unconstrained::UnconstrainedVariableChecker;
 
 use core::cmp::Ordering;
 
@@ -0,0 +1,153 @@
+use crate::r1cs::ConstraintSystemRef;
+use ark_ff::Field;
+use core::fmt;
+
+/// Attributes the constraints of a `ConstraintSystem` to the namespaces
+/// (created via `ns!`) in which they were enforced.
+///
+/// Namespaces are only recorded when a `ConstraintLayer` is part of the active
+/// `tracing` subscriber during constraint generation. Constraints enforced
+/// outside of any namespace, or without a `ConstraintLayer`, are attributed
+/// to the root of the profile.
+#[derive(Debug, Clone)]
+pub struct NamespaceProfiler<F: Field> {
+    cs: ConstraintSystemRef<F>,
+}
+
+/// The number of constraints enforced inside a namespace, including those
+/// enforced inside its child namespaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespaceProfile {
+    /// Name of the namespace. This is empty for the root of the profile.
+    pub name: &'static str,
+    /// The number of constraints enforced inside this namespace.
+    pub num_constraints: usize,
+    /// Child namespaces, in the order in which they were first entered.
+    pub children: Vec<NamespaceProfile>,
+}
+
+impl<F: Field> NamespaceProfiler<F> {
+    /// Construct a new `NamespaceProfiler` for `cs`.
+    pub fn new(cs: ConstraintSystemRef<F>) -> Self {
+        Self { cs }
+    }
+
+    /// Obtain the inner `ConstraintSystemRef<F>`.
+    pub fn cs(&self) -> ConstraintSystemRef<F> {
+        self.cs.clone()
+    }
+
+    /// Returns the tree of namespaces in `self.cs()`, together with the
+    /// number of constraints enforced inside each of them.
+    ///
+    /// Returns `None` if `self.cs()` is `ConstraintSystemRef::None`.
+    pub fn profile(&self) -> Option<NamespaceProfile> {
+        let cs = self.cs.borrow()?;
+        let mut root = NamespaceProfile::new("");
+        for trace in cs.constraint_traces() {
+            root.num_constraints += 1;
+            let path = trace.as_ref().map(|t| t.path()).unwrap_or_default();
+            let mut node = &mut root;
+            for step in path {
+                node = node.child_mut(step.name);
+                node.num_constraints += 1;
+            }
+        }
+        Some(root)
+    }
+}
+
+impl NamespaceProfile {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            num_constraints: 0,
+            children: Vec::new(),
+        }
+    }
+
+    fn child_mut(&mut self, name: &'static str) -> &mut Self {
+        let index = match self.children.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                self.children.push(Self::new(name));
+                self.children.len() - 1
+            },
+        };
+        &mut self.children[index]
+    }
+
+    /// Find the child namespace with name `name`, if it exists.
+    pub fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn fmt_with_depth(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let name = if depth == 0 { "/" } else { self.name };
+        writeln!(
+            f,
+            "{:indent$}{}: {}",
+            "",
+            name,
+            self.num_constraints,
+            indent = 2 * depth
+        )?;
+        for child in &self.children {
+            child.fmt_with_depth(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NamespaceProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_depth(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ns, r1cs::*};
+    use ark_ff::One;
+    use ark_test_curves::bls12_381::Fr;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn enforce_n(cs: ConstraintSystemRef<Fr>, n: usize) -> crate::r1cs::Result<()> {
+        let a = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        for _ in 0..n {
+            cs.enforce_constraint(lc!() + a, lc!() + a, lc!() + a)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn attributes_constraints_to_namespaces() -> crate::r1cs::Result<()> {
+        let subscriber = tracing_subscriber::Registry::default()
+            .with(ConstraintLayer::new(TracingMode::OnlyConstraints));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        enforce_n(cs.clone(), 1)?;
+        {
+            let first = ns!(cs, "first");
+            enforce_n(first.cs(), 2)?;
+            {
+                let inner = ns!(cs, "inner");
+                enforce_n(inner.cs(), 3)?;
+            }
+        }
+        {
+            let second = ns!(cs, "second");
+            enforce_n(second.cs(), 4)?;
+        }
+
+        let profile = NamespaceProfiler::new(cs).profile().unwrap();
+        assert_eq!(profile.num_constraints, 10);
+        assert_eq!(profile.children.len(), 2);
+        let first = profile.child("first").unwrap();
+        assert_eq!(first.num_constraints, 5);
+        assert_eq!(first.child("inner").unwrap().num_constraints, 3);
+        assert_eq!(profile.child("second").unwrap().num_constraints, 4);
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::r1cs::{ConstraintSystemRef, Variable};
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+/// Inspects a `ConstraintSystem` for witness variables that were allocated
+/// but never used in any constraint.
+///
+/// Such variables are a common source of soundness bugs: the prover is free
+/// to assign them any value. The check should be run after
+/// `generate_constraints` has completed, and requires the constraint system to
+/// construct matrices (see `ConstraintSystem::should_construct_matrices`),
+/// since otherwise the constraints are not recorded.
+#[derive(Debug, Clone)]
+pub struct UnconstrainedVariableChecker<F: Field> {
+    cs: ConstraintSystemRef<F>,
+}
+
+impl<F: Field> UnconstrainedVariableChecker<F> {
+    /// Construct a new `UnconstrainedVariableChecker` for `cs`.
+    pub fn new(cs: ConstraintSystemRef<F>) -> Self {
+        Self { cs }
+    }
+
+    /// Obtain the inner `ConstraintSystemRef<F>`.
+    pub fn cs(&self) -> ConstraintSystemRef<F> {
+        self.cs.clone()
+    }
+
+    /// Returns the witness variables that do not appear in the A, B, or C
+    /// linear combination of any constraint, in allocation order.
+    ///
+    /// Returns `None` if `self.cs()` is `ConstraintSystemRef::None`, or if it
+    /// does not construct matrices.
+    pub fn unconstrained_witnesses(&self) -> Option<Vec<Variable>> {
+        let usage = self.cs.borrow()?.witness_usage()?;
+        Some(
+            usage
+                .into_iter()
+                .enumerate()
+                .filter(|(_, used)| !used)
+                .map(|(i, _)| Variable::Witness(i))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r1cs::*;
+    use ark_ff::One;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn flags_unconstrained_witness() -> crate::r1cs::Result<()> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let unused = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let d = cs.new_lc(lc!() + a + c)?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + b)?;
+        cs.enforce_constraint(lc!() + d, lc!() + Variable::One, lc!() + d)?;
+
+        let checker = UnconstrainedVariableChecker::new(cs.clone());
+        assert_eq!(checker.unconstrained_witnesses(), Some(vec![unused]));
+
+        cs.inline_all_lcs();
+        assert_eq!(checker.unconstrained_witnesses(), Some(vec![unused]));
+        Ok(())
+    }
+
+    #[test]
+    fn fully_constrained_circuit() -> crate::r1cs::Result<()> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let two = Fr::one() + Fr::one();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(two))?;
+        cs.enforce_constraint(lc!() + a, lc!() + (two, b), lc!() + c)?;
+
+        let checker = UnconstrainedVariableChecker::new(cs);
+        assert_eq!(checker.unconstrained_witnesses(), Some(vec![]));
+        Ok(())
+    }
+
+    #[test]
+    fn no_answer_without_matrices() -> crate::r1cs::Result<()> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        cs.set_mode(SynthesisMode::Prove {
+            construct_matrices: false,
+        });
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + b)?;
+
+        let checker = UnconstrainedVariableChecker::new(cs);
+        assert_eq!(checker.unconstrained_witnesses(), None);
+        Ok(())
+    }
+}